@@ -0,0 +1,66 @@
+//! The agent pipeline: loads a task spec and drives it step by step, independent of
+//! whether it's invoked from the GUI or the headless CLI ([`crate::cli`]).
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// A single agent task, as loaded from a `--task <file>` JSON spec.
+#[derive(Debug, Deserialize)]
+pub struct TaskSpec {
+  pub provider: String,
+  pub model: String,
+  pub prompt: String,
+  #[serde(default = "default_max_steps")]
+  pub max_steps: usize,
+}
+
+fn default_max_steps() -> usize {
+  10
+}
+
+/// One entry in the transcript produced while running a task.
+#[derive(Debug, Serialize)]
+pub struct StepResult {
+  pub step: usize,
+  pub output: String,
+}
+
+/// The structured result printed to stdout in headless mode.
+#[derive(Debug, Serialize)]
+pub struct AgentResult {
+  pub success: bool,
+  pub steps: Vec<StepResult>,
+  pub error: Option<String>,
+}
+
+/// Reads and parses a task spec from disk.
+pub fn load_task_spec(path: &Path) -> Result<TaskSpec, String> {
+  let raw = std::fs::read_to_string(path).map_err(|e| format!("failed to read task file: {e}"))?;
+  serde_json::from_str(&raw).map_err(|e| format!("invalid task spec: {e}"))
+}
+
+/// Runs all `max_steps` steps of a task, producing the full transcript.
+///
+/// Each step's output echoes the provider, model and prompt; the result always has
+/// `success: true` and no `error`. The headless CLI is the only caller; no command
+/// exposes it to the windowed UI.
+pub async fn run_task(spec: TaskSpec) -> AgentResult {
+  let mut steps = Vec::new();
+
+  for step in 0..spec.max_steps {
+    steps.push(StepResult {
+      step,
+      output: format!(
+        "[{}/{}] {} (step {step})",
+        spec.provider, spec.model, spec.prompt
+      ),
+    });
+  }
+
+  AgentResult {
+    success: true,
+    steps,
+    error: None,
+  }
+}
@@ -0,0 +1,67 @@
+//! Headless/agent CLI mode: run a task spec to completion without showing a window.
+//!
+//! Wired from the `setup` closure in [`crate::run`] via `tauri_plugin_cli`'s
+//! `CliExt::cli().matches()`. When `--task <file>` (or the `--headless` flag) is
+//! present we load the spec, drive [`crate::agent::run_task`] to completion, print the
+//! result as JSON, and exit the process instead of falling through to the normal window.
+
+use std::path::PathBuf;
+
+use tauri::Manager;
+use tauri_plugin_cli::CliExt;
+
+use crate::agent;
+
+/// Inspects the parsed CLI matches and, if headless mode was requested, runs the task
+/// to completion and exits the process. Returns control to the caller unchanged when
+/// no CLI flags were given, so normal windowed startup proceeds.
+pub fn handle(app: &tauri::App) -> tauri::Result<()> {
+  let matches = match app.cli().matches() {
+    Ok(matches) => matches,
+    // No CLI schema matched (e.g. running under the test harness) - just start the window.
+    Err(_) => return Ok(()),
+  };
+
+  // Every declared arg is always present in `matches.args` (an absent flag is
+  // `Bool(false)`, an absent value-arg is `Null`), so we must gate on the parsed value,
+  // not on key presence - otherwise a plain launch with no flags would match too.
+  let task_value = matches.args.get("task").and_then(|a| a.value.as_str());
+  let headless_flag = matches
+    .args
+    .get("headless")
+    .and_then(|a| a.value.as_bool())
+    .unwrap_or(false);
+
+  if !headless_flag && task_value.is_none() {
+    return Ok(());
+  }
+
+  let task_path = match task_value {
+    Some(path) => PathBuf::from(path),
+    None => {
+      eprintln!("--headless requires --task <file>");
+      std::process::exit(1);
+    }
+  };
+
+  let spec = match agent::load_task_spec(&task_path) {
+    Ok(spec) => spec,
+    Err(err) => {
+      eprintln!("{err}");
+      std::process::exit(1);
+    }
+  };
+
+  // `app.cli().matches()` runs inside the synchronous `setup` closure, so we block on
+  // Tauri's async runtime rather than requiring the whole closure to be async.
+  let result = tauri::async_runtime::block_on(agent::run_task(spec));
+  let exit_code = if result.success { 0 } else { 1 };
+
+  println!(
+    "{}",
+    serde_json::to_string(&result).expect("agent result is always serializable")
+  );
+
+  app.handle().exit(exit_code);
+  Ok(())
+}
@@ -1,3 +1,11 @@
+mod agent;
+mod cli;
+mod menu;
+mod session;
+mod vault;
+
+use tauri::{Manager, WindowEvent};
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
@@ -9,24 +17,37 @@ pub fn run() {
             .build(),
         )?;
       }
+      menu::setup(app.handle())?;
+      if let Err(err) = session::restore_last(app.handle()) {
+        log::warn!("failed to restore last session: {err}");
+      }
+      cli::handle(app)?;
       Ok(())
     })
+    .on_menu_event(menu::on_event)
+    .on_window_event(|window, event| {
+      if matches!(event, WindowEvent::CloseRequested { .. }) {
+        if let Err(err) = session::flush(&window.app_handle()) {
+          log::error!("failed to autosave session on close: {err}");
+        }
+      }
+    })
     .plugin(tauri_plugin_store::Builder::default().build())
-    .plugin(
-      tauri_plugin_stronghold::Builder::new(|password| {
-        // Using a simple hash for demo - in production, use argon2 or similar
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        password.hash(&mut hasher);
-        let hash = hasher.finish();
-        
-        // Convert to bytes for the key
-        hash.to_le_bytes().to_vec()
-      })
-      .build(),
-    )
+    .plugin(tauri_plugin_cli::init())
+    .manage(vault::VaultState::default())
+    .manage(session::SessionState::default())
+    .invoke_handler(tauri::generate_handler![
+      vault::vault_unlock,
+      vault::vault_store_secret,
+      vault::vault_get_secret,
+      vault::vault_list_keys,
+      vault::vault_delete_secret,
+      vault::vault_lock,
+      session::session_save,
+      session::session_load,
+      session::session_list,
+      session::session_clear,
+    ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
@@ -0,0 +1,64 @@
+//! Native application menu (and tray) with agent/vault controls.
+//!
+//! Built once in the `setup` closure in [`crate::run`] and shared between the window
+//! menu bar and the tray icon so both surfaces stay in sync. Menu item IDs are
+//! dispatched in [`on_event`]: vault locking goes straight through the existing
+//! [`crate::vault`] command layer, the rest are emitted as window events for the
+//! frontend to handle.
+
+use tauri::menu::{Menu, MenuBuilder, MenuEvent, MenuItemBuilder};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Emitter, Manager, Wry};
+
+use crate::vault::{self, VaultState};
+
+const NEW_SESSION: &str = "new_session";
+const OPEN_VAULT: &str = "open_vault";
+const LOCK_VAULT: &str = "lock_vault";
+const EXPORT_TRANSCRIPT: &str = "export_transcript";
+const STOP_AGENT: &str = "stop_agent";
+
+/// Builds the "Agent" menu shared by the window menu bar and the tray icon.
+fn build(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
+  MenuBuilder::new(app)
+    .item(&MenuItemBuilder::with_id(NEW_SESSION, "New Session").build(app)?)
+    .item(&MenuItemBuilder::with_id(OPEN_VAULT, "Open Vault").build(app)?)
+    .item(&MenuItemBuilder::with_id(LOCK_VAULT, "Lock Vault").build(app)?)
+    .item(&MenuItemBuilder::with_id(EXPORT_TRANSCRIPT, "Export Transcript").build(app)?)
+    .item(&MenuItemBuilder::with_id(STOP_AGENT, "Stop Running Agent").build(app)?)
+    .build()
+}
+
+/// Installs the application menu and a tray icon backed by the same menu.
+pub fn setup(app: &AppHandle) -> tauri::Result<()> {
+  let menu = build(app)?;
+  app.set_menu(menu.clone())?;
+
+  TrayIconBuilder::new()
+    .menu(&menu)
+    .on_menu_event(on_event)
+    .build(app)?;
+
+  Ok(())
+}
+
+/// Routes a menu/tray item click to the vault command layer or a window event.
+pub fn on_event(app: &AppHandle, event: MenuEvent) {
+  match event.id().as_ref() {
+    LOCK_VAULT => {
+      let state = app.state::<VaultState>();
+      if let Err(err) = vault::vault_lock(state) {
+        log::error!("failed to lock vault from menu: {err}");
+      }
+    }
+    // These have no standalone command; the frontend owns the resulting behavior.
+    NEW_SESSION | OPEN_VAULT | EXPORT_TRANSCRIPT | STOP_AGENT => {
+      if let Some(window) = app.get_webview_window("main") {
+        if let Err(err) = window.emit(event.id().as_ref(), ()) {
+          log::error!("failed to emit menu event `{}`: {err}", event.id().as_ref());
+        }
+      }
+    }
+    _ => {}
+  }
+}
@@ -0,0 +1,215 @@
+//! Durable agent sessions, persisted through `tauri_plugin_store` so a conversation
+//! survives a crash or a normal quit and can be resumed where it left off.
+//!
+//! Sessions are stored as a `HashMap<String, Session>` (keyed by session id) plus a
+//! `last_session_id` pointer in a single store file. The current version's shape is
+//! versioned so an older stored session can be migrated forward instead of rejected.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_store::StoreExt;
+
+/// Store file all sessions live in, relative to the app's data directory.
+const STORE_FILE: &str = "sessions.json";
+/// Key the sessions map is stored under within [`STORE_FILE`].
+const SESSIONS_KEY: &str = "sessions";
+/// Key pointing at the session that should be restored on next launch.
+const LAST_SESSION_KEY: &str = "last_session_id";
+
+/// Bump whenever [`Session`]'s shape changes, and extend [`migrate`] to upgrade any
+/// stored value whose `version` is older than this.
+const CURRENT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+  pub role: String,
+  pub content: String,
+}
+
+/// A single resumable agent session: what was said, with which provider/model, and
+/// how far the run had gotten.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+  #[serde(default = "default_version")]
+  pub version: u32,
+  pub id: String,
+  pub provider: String,
+  pub model: String,
+  #[serde(default)]
+  pub transcript: Vec<TranscriptEntry>,
+  #[serde(default)]
+  pub step_index: usize,
+}
+
+fn default_version() -> u32 {
+  CURRENT_VERSION
+}
+
+/// Lightweight listing entry returned by [`session_list`], without the full transcript.
+#[derive(Debug, Serialize)]
+pub struct SessionSummary {
+  pub id: String,
+  pub provider: String,
+  pub model: String,
+  pub step_index: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum SessionError {
+  NotFound(String),
+  Store(String),
+}
+
+impl std::fmt::Display for SessionError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SessionError::NotFound(id) => write!(f, "no session stored for `{id}`"),
+      SessionError::Store(msg) => write!(f, "session store error: {msg}"),
+    }
+  }
+}
+
+impl std::error::Error for SessionError {}
+
+/// Upgrades a raw stored session value to the current [`Session`] shape.
+///
+/// `version` 0 (the implicit version of anything stored before this field existed)
+/// only needs `step_index` defaulted, which `serde(default)` already covers - this
+/// exists as the seam future migrations hang off, so a version bump never means
+/// silently dropping older sessions.
+fn migrate(mut value: Value) -> Value {
+  let version = value.get("version").and_then(Value::as_u64).unwrap_or(0);
+  if version < 1 {
+    value["version"] = json!(1);
+  }
+  value
+}
+
+/// Holds the session currently being driven by the app, so it can be flushed to disk
+/// on window close without the frontend having to resend the whole transcript.
+#[derive(Default)]
+pub struct SessionState(Mutex<Option<Session>>);
+
+fn sessions_map(app: &AppHandle) -> Result<serde_json::Map<String, Value>, SessionError> {
+  let store = app
+    .store(STORE_FILE)
+    .map_err(|e| SessionError::Store(e.to_string()))?;
+
+  Ok(match store.get(SESSIONS_KEY) {
+    Some(Value::Object(map)) => map,
+    _ => serde_json::Map::new(),
+  })
+}
+
+/// Persists a session and marks it as the one to restore on next launch.
+#[tauri::command]
+pub fn session_save(
+  app: AppHandle,
+  state: State<'_, SessionState>,
+  session: Session,
+) -> Result<(), SessionError> {
+  let store = app
+    .store(STORE_FILE)
+    .map_err(|e| SessionError::Store(e.to_string()))?;
+
+  let mut sessions = sessions_map(&app)?;
+  sessions.insert(
+    session.id.clone(),
+    serde_json::to_value(&session).map_err(|e| SessionError::Store(e.to_string()))?,
+  );
+
+  store.set(SESSIONS_KEY, Value::Object(sessions));
+  store.set(LAST_SESSION_KEY, json!(session.id));
+  store.save().map_err(|e| SessionError::Store(e.to_string()))?;
+
+  *state.0.lock().unwrap() = Some(session);
+  Ok(())
+}
+
+/// Loads a session by id, migrating it forward if it was written by an older version.
+#[tauri::command]
+pub fn session_load(app: AppHandle, id: String) -> Result<Session, SessionError> {
+  let sessions = sessions_map(&app)?;
+  let raw = sessions
+    .get(&id)
+    .cloned()
+    .ok_or_else(|| SessionError::NotFound(id.clone()))?;
+
+  serde_json::from_value(migrate(raw)).map_err(|e| SessionError::Store(e.to_string()))
+}
+
+/// Lists every stored session as a lightweight summary (no transcript bodies).
+#[tauri::command]
+pub fn session_list(app: AppHandle) -> Result<Vec<SessionSummary>, SessionError> {
+  let sessions = sessions_map(&app)?;
+
+  sessions
+    .values()
+    .cloned()
+    .map(|raw| {
+      serde_json::from_value::<Session>(migrate(raw)).map(|s| SessionSummary {
+        id: s.id,
+        provider: s.provider,
+        model: s.model,
+        step_index: s.step_index,
+      })
+    })
+    .collect::<Result<_, _>>()
+    .map_err(|e| SessionError::Store(e.to_string()))
+}
+
+/// Deletes a stored session. Clearing the currently restored session also clears the
+/// "resume on launch" pointer.
+#[tauri::command]
+pub fn session_clear(
+  app: AppHandle,
+  state: State<'_, SessionState>,
+  id: String,
+) -> Result<(), SessionError> {
+  let store = app
+    .store(STORE_FILE)
+    .map_err(|e| SessionError::Store(e.to_string()))?;
+
+  let mut sessions = sessions_map(&app)?;
+  sessions.remove(&id);
+  store.set(SESSIONS_KEY, Value::Object(sessions));
+
+  if store.get(LAST_SESSION_KEY).and_then(|v| v.as_str().map(String::from)) == Some(id.clone()) {
+    store.delete(LAST_SESSION_KEY);
+  }
+  store.save().map_err(|e| SessionError::Store(e.to_string()))?;
+
+  let mut guard = state.0.lock().unwrap();
+  if guard.as_ref().is_some_and(|s| s.id == id) {
+    *guard = None;
+  }
+  Ok(())
+}
+
+/// Restores the last open session (if any) into [`SessionState`] on startup.
+pub fn restore_last(app: &AppHandle) -> Result<Option<Session>, SessionError> {
+  let store = app
+    .store(STORE_FILE)
+    .map_err(|e| SessionError::Store(e.to_string()))?;
+
+  let Some(id) = store.get(LAST_SESSION_KEY).and_then(|v| v.as_str().map(String::from)) else {
+    return Ok(None);
+  };
+
+  let session = session_load(app.clone(), id)?;
+  *app.state::<SessionState>().0.lock().unwrap() = Some(session.clone());
+  Ok(Some(session))
+}
+
+/// Flushes the in-memory session to disk, e.g. on window close.
+pub fn flush(app: &AppHandle) -> Result<(), SessionError> {
+  let session = app.state::<SessionState>().0.lock().unwrap().clone();
+  match session {
+    Some(session) => session_save(app.clone(), app.state::<SessionState>(), session),
+    None => Ok(()),
+  }
+}
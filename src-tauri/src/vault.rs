@@ -0,0 +1,284 @@
+//! Stronghold-backed secret storage for agent/provider API keys.
+//!
+//! Secrets are namespaced as `<provider>.<key>` (e.g. `openai.api_key`,
+//! `anthropic.api_key`) and stored in a single Stronghold client's key/value
+//! store. The vault starts locked; the frontend unlocks it once per session
+//! with [`vault_unlock`], and every other command except [`vault_lock`] fails
+//! with [`VaultError::Locked`] until that happens.
+//!
+//! `vault_unlock` opens the snapshot itself (via the `stronghold` crate directly)
+//! rather than going through `tauri_plugin_stronghold`, so there is exactly one
+//! snapshot path and one Argon2id-derived key in play - the plugin is not
+//! registered in `run()`.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use argon2::Argon2;
+use rand::RngCore;
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_stronghold::stronghold::{Client, KeyProvider, SnapshotPath, Stronghold};
+
+/// Length in bytes of the key handed to Stronghold for snapshot encryption.
+const KEY_LEN: usize = 32;
+/// Length in bytes of the on-disk salt. 16 bytes is the minimum Argon2 recommends.
+const SALT_LEN: usize = 16;
+
+/// Argon2id tuning. Kept as explicit constants (rather than library defaults) so a
+/// future change can be versioned and old snapshots migrated deliberately instead of
+/// silently changing the derived key.
+const ARGON2_M_COST: u32 = 19_456; // KiB
+const ARGON2_T_COST: u32 = 2;
+const ARGON2_P_COST: u32 = 1;
+
+/// Name of the single Stronghold client all secrets are stored under.
+const CLIENT_PATH: &[u8] = b"agent-dojo-vault";
+/// Store key holding the JSON-encoded list of namespaced secret keys, since
+/// Stronghold's key/value store has no native enumeration.
+const INDEX_KEY: &[u8] = b"__vault_index__";
+
+/// The directory the vault's snapshot and salt both live in, so the salt is always
+/// tied to the same app-path base as the snapshot it protects.
+fn vault_dir(app: &AppHandle) -> Result<PathBuf, String> {
+  app.path().app_local_data_dir().map_err(|e| e.to_string())
+}
+
+fn salt_path(app: &AppHandle) -> Result<PathBuf, String> {
+  Ok(vault_dir(app)?.join("vault.salt"))
+}
+
+/// Loads the vault's salt from disk, generating and persisting a new one on first run.
+///
+/// The salt MUST stay stable across launches: it is tied to the specific vault
+/// snapshot, and regenerating it would silently change the derived key and make the
+/// snapshot permanently unreadable.
+fn load_or_create_salt(app: &AppHandle) -> Result<[u8; SALT_LEN], String> {
+  let path = salt_path(app)?;
+
+  if let Ok(bytes) = fs::read(&path) {
+    if bytes.len() == SALT_LEN {
+      let mut salt = [0u8; SALT_LEN];
+      salt.copy_from_slice(&bytes);
+      return Ok(salt);
+    }
+  }
+
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+  }
+
+  let mut salt = [0u8; SALT_LEN];
+  rand::thread_rng().fill_bytes(&mut salt);
+  fs::write(&path, salt).map_err(|e| e.to_string())?;
+  Ok(salt)
+}
+
+/// Derives the 32-byte Stronghold snapshot key from the user's password via Argon2id.
+///
+/// Returns an error instead of panicking so a read-only data directory or a KDF
+/// failure surfaces to the caller (e.g. as `VaultError::Stronghold` from
+/// `vault_unlock`) rather than aborting the process.
+pub fn derive_stronghold_key(app: &AppHandle, password: &str) -> Result<Vec<u8>, String> {
+  let salt = load_or_create_salt(app)?;
+
+  let params = argon2::Params::new(ARGON2_M_COST, ARGON2_T_COST, ARGON2_P_COST, Some(KEY_LEN))
+    .map_err(|e| format!("invalid argon2 params: {e}"))?;
+  let argon2 = Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+
+  let mut key = [0u8; KEY_LEN];
+  argon2
+    .hash_password_into(password.as_bytes(), &salt, &mut key)
+    .map_err(|e| format!("argon2 key derivation failed: {e}"))?;
+  Ok(key.to_vec())
+}
+
+/// Error surface for vault commands. Serialized as `{ "kind": "...", "message": "..." }`
+/// so the frontend can match on `kind` instead of parsing strings.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum VaultError {
+  /// A command that needs secret access ran before `vault_unlock`.
+  Locked,
+  /// No secret stored under the requested namespaced key.
+  NotFound(String),
+  /// The underlying Stronghold snapshot or client operation failed.
+  Stronghold(String),
+}
+
+impl std::fmt::Display for VaultError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      VaultError::Locked => write!(f, "vault is locked"),
+      VaultError::NotFound(key) => write!(f, "no secret stored for `{key}`"),
+      VaultError::Stronghold(msg) => write!(f, "stronghold error: {msg}"),
+    }
+  }
+}
+
+impl std::error::Error for VaultError {}
+
+/// The unlocked vault session: the Stronghold instance and the single client all
+/// secrets live in, plus what's needed to re-commit the snapshot to disk (the path and
+/// the key it's encrypted with) without re-deriving the key from the password again.
+struct Session {
+  stronghold: Stronghold,
+  client: Client,
+  snapshot_path: SnapshotPath,
+  key_provider: KeyProvider,
+}
+
+/// Tauri-managed state tracking whether the vault is currently unlocked.
+#[derive(Default)]
+pub struct VaultState(Mutex<Option<Session>>);
+
+fn namespaced_key(provider: &str, key: &str) -> String {
+  format!("{provider}.{key}")
+}
+
+fn read_index(client: &Client) -> Result<Vec<String>, VaultError> {
+  match client.store().get(INDEX_KEY) {
+    Ok(Some(bytes)) => serde_json::from_slice(&bytes)
+      .map_err(|e| VaultError::Stronghold(format!("corrupt vault index: {e}"))),
+    Ok(None) => Ok(Vec::new()),
+    Err(e) => Err(VaultError::Stronghold(e.to_string())),
+  }
+}
+
+fn write_index(client: &Client, index: &[String]) -> Result<(), VaultError> {
+  let bytes = serde_json::to_vec(index)
+    .map_err(|e| VaultError::Stronghold(format!("failed to encode vault index: {e}")))?;
+  client
+    .store()
+    .insert(INDEX_KEY.to_vec(), bytes, None)
+    .map_err(|e| VaultError::Stronghold(e.to_string()))
+}
+
+/// Opens (or initializes) the Stronghold snapshot with the given password and stores
+/// the resulting client in [`VaultState`], unlocking the vault for this session.
+#[tauri::command]
+pub fn vault_unlock(
+  app: AppHandle,
+  state: State<'_, VaultState>,
+  password: String,
+) -> Result<(), VaultError> {
+  let key = derive_stronghold_key(&app, &password).map_err(VaultError::Stronghold)?;
+  let dir = vault_dir(&app).map_err(VaultError::Stronghold)?;
+  fs::create_dir_all(&dir).map_err(|e| VaultError::Stronghold(e.to_string()))?;
+  let snapshot_path = SnapshotPath::from_path(dir.join("vault.stronghold"));
+  let key_provider =
+    KeyProvider::try_from(key).map_err(|e| VaultError::Stronghold(e.to_string()))?;
+
+  let stronghold = Stronghold::default();
+  let client = if snapshot_path.exists() {
+    stronghold
+      .load_client_from_snapshot(CLIENT_PATH, &key_provider, &snapshot_path)
+      .map_err(|e| VaultError::Stronghold(e.to_string()))?
+  } else {
+    stronghold
+      .create_client(CLIENT_PATH)
+      .map_err(|e| VaultError::Stronghold(e.to_string()))?
+  };
+
+  *state.0.lock().unwrap() = Some(Session {
+    stronghold,
+    client,
+    snapshot_path,
+    key_provider,
+  });
+  Ok(())
+}
+
+/// Stores a secret under `<provider>.<key>`, overwriting any existing value.
+#[tauri::command]
+pub fn vault_store_secret(
+  state: State<'_, VaultState>,
+  provider: String,
+  key: String,
+  value: String,
+) -> Result<(), VaultError> {
+  let guard = state.0.lock().unwrap();
+  let session = guard.as_ref().ok_or(VaultError::Locked)?;
+  let namespaced = namespaced_key(&provider, &key);
+
+  session
+    .client
+    .store()
+    .insert(namespaced.clone().into_bytes(), value.into_bytes(), None)
+    .map_err(|e| VaultError::Stronghold(e.to_string()))?;
+
+  let mut index = read_index(&session.client)?;
+  if !index.contains(&namespaced) {
+    index.push(namespaced);
+    write_index(&session.client, &index)?;
+  }
+
+  session
+    .stronghold
+    .commit_with_keyprovider(&session.snapshot_path, &session.key_provider)
+    .map_err(|e| VaultError::Stronghold(e.to_string()))
+}
+
+/// Returns the secret stored under `<provider>.<key>`.
+#[tauri::command]
+pub fn vault_get_secret(
+  state: State<'_, VaultState>,
+  provider: String,
+  key: String,
+) -> Result<String, VaultError> {
+  let guard = state.0.lock().unwrap();
+  let session = guard.as_ref().ok_or(VaultError::Locked)?;
+  let namespaced = namespaced_key(&provider, &key);
+
+  let bytes = session
+    .client
+    .store()
+    .get(namespaced.as_bytes())
+    .map_err(|e| VaultError::Stronghold(e.to_string()))?
+    .ok_or_else(|| VaultError::NotFound(namespaced.clone()))?;
+
+  String::from_utf8(bytes).map_err(|e| VaultError::Stronghold(e.to_string()))
+}
+
+/// Lists every namespaced secret key currently stored (not the secret values).
+#[tauri::command]
+pub fn vault_list_keys(state: State<'_, VaultState>) -> Result<Vec<String>, VaultError> {
+  let guard = state.0.lock().unwrap();
+  let session = guard.as_ref().ok_or(VaultError::Locked)?;
+  read_index(&session.client)
+}
+
+/// Deletes the secret stored under `<provider>.<key>`, if any.
+#[tauri::command]
+pub fn vault_delete_secret(
+  state: State<'_, VaultState>,
+  provider: String,
+  key: String,
+) -> Result<(), VaultError> {
+  let guard = state.0.lock().unwrap();
+  let session = guard.as_ref().ok_or(VaultError::Locked)?;
+  let namespaced = namespaced_key(&provider, &key);
+
+  session
+    .client
+    .store()
+    .delete(namespaced.as_bytes())
+    .map_err(|e| VaultError::Stronghold(e.to_string()))?;
+
+  let mut index = read_index(&session.client)?;
+  index.retain(|k| k != &namespaced);
+  write_index(&session.client, &index)?;
+
+  session
+    .stronghold
+    .commit_with_keyprovider(&session.snapshot_path, &session.key_provider)
+    .map_err(|e| VaultError::Stronghold(e.to_string()))
+}
+
+/// Drops the unlocked Stronghold client, re-locking the vault for this session.
+#[tauri::command]
+pub fn vault_lock(state: State<'_, VaultState>) -> Result<(), VaultError> {
+  *state.0.lock().unwrap() = None;
+  Ok(())
+}